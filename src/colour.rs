@@ -1,4 +1,5 @@
 /// Colour is an RGB colour.
+#[derive(Clone, Copy)]
 pub struct Colour {
     comp: [f32; 3],
 }
@@ -55,6 +56,15 @@ impl std::ops::Mul<f32> for Colour {
     }
 }
 
+/// Operator overload for component-wise multiplication (used to apply attenuation).
+impl std::ops::Mul<Colour> for Colour {
+    type Output = Colour;
+
+    fn mul(self, arg: Colour) -> Colour {
+        Colour::new(self.r() * arg.r(), self.g() * arg.g(), self.b() * arg.b())
+    }
+}
+
 /// Operator overload for division.
 impl std::ops::Div<f32> for Colour {
     type Output = Colour;
@@ -0,0 +1,46 @@
+use crate::{ray::Ray, vec3::Vec3};
+
+/// Aabb is an axis-aligned bounding box.
+#[derive(Clone, Copy)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb {
+    /// Constructor.
+    pub fn new(min: Vec3, max: Vec3) -> Self {
+        Aabb { min, max }
+    }
+
+    /// hit returns true if the ray intersects the box within `[t_min, t_max]`.
+    pub fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> bool {
+        let mut t_min = t_min;
+        let mut t_max = t_max;
+
+        for axis in 0..3 {
+            let inv_d = 1.0 / ray.dir.axis(axis);
+
+            let mut t0 = (self.min.axis(axis) - ray.origin.axis(axis)) * inv_d;
+            let mut t1 = (self.max.axis(axis) - ray.origin.axis(axis)) * inv_d;
+
+            if inv_d < 0.0 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+
+            t_min = t_min.max(t0);
+            t_max = t_max.min(t1);
+
+            if t_max <= t_min {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// surrounding_box returns the smallest box that contains both `a` and `b`.
+    pub fn surrounding_box(a: &Aabb, b: &Aabb) -> Aabb {
+        Aabb::new(a.min.min(&b.min), a.max.max(&b.max))
+    }
+}
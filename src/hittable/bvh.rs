@@ -0,0 +1,100 @@
+use crate::{
+    hittable::{Aabb, Hit, Hittable},
+    ray::Ray,
+};
+
+/// BvhNode is a node of a bounding volume hierarchy: either an empty node
+/// that never hits anything, a leaf wrapping a single hittable object, or an
+/// interior node splitting its children's bounding boxes in two.
+pub enum BvhNode {
+    /// Empty is the BVH of zero objects: it reports no hit and no bounds.
+    Empty,
+    Leaf(Box<dyn Hittable>),
+    Node {
+        left: Box<BvhNode>,
+        right: Box<BvhNode>,
+        bbox: Aabb,
+    },
+}
+
+impl BvhNode {
+    /// new recursively partitions `objects` into a BVH.
+    pub fn new(mut objects: Vec<Box<dyn Hittable>>) -> BvhNode {
+        if objects.is_empty() {
+            return BvhNode::Empty;
+        }
+        if objects.len() == 1 {
+            return BvhNode::Leaf(objects.pop().unwrap());
+        }
+
+        // Pick the axis along which the objects' combined bounding box is widest.
+        let axis = Self::longest_extent_axis(&objects);
+
+        // Sort primitives by their box min on that axis, then split in half.
+        objects.sort_by(|a, b| {
+            let a_min = a.bounding_box().unwrap().min.axis(axis);
+            let b_min = b.bounding_box().unwrap().min.axis(axis);
+            a_min.partial_cmp(&b_min).unwrap()
+        });
+
+        let right_objects = objects.split_off(objects.len() / 2);
+
+        let left = Box::new(BvhNode::new(objects));
+        let right = Box::new(BvhNode::new(right_objects));
+        let bbox = Aabb::surrounding_box(
+            &left.bounding_box().unwrap(),
+            &right.bounding_box().unwrap(),
+        );
+
+        BvhNode::Node { left, right, bbox }
+    }
+
+    /// longest_extent_axis returns the axis (0 = x, 1 = y, 2 = z) along which
+    /// the objects' combined bounding box is widest.
+    fn longest_extent_axis(objects: &[Box<dyn Hittable>]) -> usize {
+        let bounds = objects
+            .iter()
+            .map(|object| object.bounding_box().unwrap())
+            .reduce(|a, b| Aabb::surrounding_box(&a, &b))
+            .expect("BvhNode requires at least one object");
+
+        let extent = bounds.max - bounds.min;
+        let mut axis = 0;
+        for candidate in 1..3 {
+            if extent.axis(candidate) > extent.axis(axis) {
+                axis = candidate;
+            }
+        }
+        axis
+    }
+}
+
+impl Hittable for BvhNode {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<Hit> {
+        match self {
+            BvhNode::Empty => None,
+            BvhNode::Leaf(object) => object.hit(ray, t_min, t_max),
+            BvhNode::Node { left, right, bbox } => {
+                if !bbox.hit(ray, t_min, t_max) {
+                    return None;
+                }
+
+                // Recurse into both children, keeping the closer t_max so the
+                // second lookup only considers hits nearer than the first.
+                let left_hit = left.hit(ray, t_min, t_max);
+                let closest = left_hit.as_ref().map_or(t_max, |hit| hit.t);
+                let right_hit = right.hit(ray, t_min, closest);
+
+                right_hit.or(left_hit)
+            }
+        }
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        match self {
+            BvhNode::Empty => None,
+            BvhNode::Leaf(object) => object.bounding_box(),
+            BvhNode::Node { bbox, .. } => Some(*bbox),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+mod aabb;
+mod bvh;
+mod sphere;
+
+use std::sync::Arc;
+
+use crate::{material::Material, ray::Ray, vec3::Vec3};
+
+pub use aabb::Aabb;
+pub use bvh::BvhNode;
+pub use sphere::Sphere;
+
+/// Hit carries the information about a ray-object intersection.
+pub struct Hit {
+    /// point is the point of intersection.
+    pub point: Vec3,
+    /// normal is the surface normal at the point of intersection.
+    /// It always points against the incident ray (see `front_face`).
+    pub normal: Vec3,
+    /// t is the ray parameter at which the intersection occurred.
+    pub t: f32,
+    /// front_face is true if the ray hit the outer surface of the object.
+    pub front_face: bool,
+    /// material of the object that was hit.
+    pub material: Arc<dyn Material>,
+}
+
+impl Hit {
+    /// new constructs a Hit, deriving `front_face` and flipping the normal
+    /// (if required) so that it always points against the incident ray.
+    pub fn new(
+        ray: &Ray,
+        point: Vec3,
+        outward_normal: Vec3,
+        t: f32,
+        material: Arc<dyn Material>,
+    ) -> Self {
+        let front_face = ray.dir.dot(&outward_normal) < 0.0;
+        let normal = if front_face {
+            outward_normal
+        } else {
+            -outward_normal
+        };
+
+        Hit {
+            point,
+            normal,
+            t,
+            front_face,
+            material,
+        }
+    }
+}
+
+/// Hittable is implemented by anything a Ray can intersect.
+/// `Send + Sync` is required so a world can be shared across render threads.
+pub trait Hittable: Send + Sync {
+    /// hit returns the closest intersection of the ray with this object
+    /// within the `[t_min, t_max]` interval, if any.
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<Hit>;
+
+    /// bounding_box returns the smallest Aabb enclosing this object, or
+    /// `None` if the object has no meaningful bounds (e.g. an empty list).
+    fn bounding_box(&self) -> Option<Aabb>;
+}
+
+/// HittableList is a collection of hittable objects, itself Hittable.
+/// Its `hit` method returns the closest intersection among all its members.
+pub struct HittableList(pub Vec<Box<dyn Hittable>>);
+
+impl HittableList {
+    /// new constructs an empty HittableList.
+    pub fn new() -> Self {
+        HittableList(Vec::new())
+    }
+
+    /// push adds a hittable object to the list.
+    pub fn push(&mut self, object: Box<dyn Hittable>) {
+        self.0.push(object)
+    }
+
+    /// into_bvh consumes the list and builds a BvhNode out of its objects,
+    /// replacing the linear O(n) scan with O(log n) tree traversal. An empty
+    /// list builds a BvhNode that never hits anything, rather than panicking.
+    pub fn into_bvh(self) -> BvhNode {
+        BvhNode::new(self.0)
+    }
+}
+
+impl Default for HittableList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hittable for HittableList {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<Hit> {
+        let mut closest = t_max;
+        let mut result = None;
+
+        for object in &self.0 {
+            if let Some(hit) = object.hit(ray, t_min, closest) {
+                closest = hit.t;
+                result = Some(hit);
+            }
+        }
+
+        result
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let mut result: Option<Aabb> = None;
+
+        for object in &self.0 {
+            let object_box = object.bounding_box()?;
+            result = Some(match result {
+                Some(existing) => Aabb::surrounding_box(&existing, &object_box),
+                None => object_box,
+            });
+        }
+
+        result
+    }
+}
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use crate::{
+    hittable::{Aabb, Hit, Hittable},
+    material::Material,
+    ray::Ray,
+    vec3::Vec3,
+};
+
+/// Sphere is a hittable sphere primitive.
+pub struct Sphere {
+    pub center: Vec3,
+    pub radius: f32,
+    pub material: Arc<dyn Material>,
+}
+
+impl Sphere {
+    /// Constructor.
+    pub fn new(center: Vec3, radius: f32, material: Arc<dyn Material>) -> Self {
+        Sphere {
+            center,
+            radius,
+            material,
+        }
+    }
+}
+
+impl Hittable for Sphere {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<Hit> {
+        // Solve t^2 (d.d) + 2t d.(o-c) + (o-c).(o-c) - r^2 = 0 for t.
+        let oc = ray.origin - self.center;
+
+        let a = ray.dir.dot_self();
+        let half_b = oc.dot(&ray.dir);
+        let c = oc.dot_self() - self.radius * self.radius;
+
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let sqrt_d = discriminant.sqrt();
+
+        // Pick the nearest root that lies within [t_min, t_max].
+        let mut root = (-half_b - sqrt_d) / a;
+        if root < t_min || root > t_max {
+            root = (-half_b + sqrt_d) / a;
+            if root < t_min || root > t_max {
+                return None;
+            }
+        }
+
+        let point = ray.at(root);
+        let outward_normal = (point - self.center) / self.radius;
+
+        Some(Hit::new(ray, point, outward_normal, root, self.material.clone()))
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let radius_vec = Vec3::new(self.radius, self.radius, self.radius);
+        Some(Aabb::new(self.center - radius_vec, self.center + radius_vec))
+    }
+}
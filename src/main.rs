@@ -1,35 +1,27 @@
 mod camera;
 mod colour;
+mod hittable;
+mod material;
 mod random;
 mod ray;
 mod render;
+mod scene;
 mod vec3;
 
-const ASPECT_RATIO: f32 = 16.0 / 9.0;
-const IMAGE_HEIGHT: u32 = 720;
+use std::{env, process};
 
 fn main() {
-    // Create camera options.
-    let cam_opts = camera::Options {
-        look_from: vec3::Vec3::new(0., 0., 0.),
-        look_at: vec3::Vec3::new(0., 0., -1.),
-        up: vec3::Vec3::new(0., 1., 0.),
-        aspect_ratio: ASPECT_RATIO,
-        fov_vertical: 90.0,
-        aperture: 0.1,
-        focus_distance: 1.0,
-    };
+    let path = env::args().nth(1).unwrap_or_else(|| {
+        eprintln!("usage: lightshow <scene-file>");
+        process::exit(1);
+    });
 
-    // Create render options.
-    let opts = render::Options {
-        camera: camera::Camera::new(&cam_opts),
-        image_height: IMAGE_HEIGHT,
-        image_width: (IMAGE_HEIGHT as f32 * ASPECT_RATIO) as u32,
-        samples_per_pixel: 1,
-        max_diff_depth: 50,
-        output_file: "./dist/image.jpg",
-    };
+    let scene = scene::Scene::load(&path).unwrap_or_else(|err| {
+        eprintln!("failed to load scene {path}: {err}");
+        process::exit(1);
+    });
 
-    // Render.
-    render::Renderer::new(opts).render().unwrap();
-}
\ No newline at end of file
+    render::Renderer::new(scene.render_options())
+        .render()
+        .unwrap();
+}
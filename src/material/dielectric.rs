@@ -0,0 +1,53 @@
+use rand::{rngs::ThreadRng, Rng};
+
+use crate::{colour::Colour, hittable::Hit, material::Material, ray::Ray};
+
+/// Dielectric is a clear material (glass, water, ...) that refracts rays,
+/// reflecting them instead when total internal reflection occurs.
+pub struct Dielectric {
+    /// refractive_index of the material (e.g. ~1.5 for glass).
+    pub refractive_index: f32,
+}
+
+impl Dielectric {
+    /// Constructor.
+    pub fn new(refractive_index: f32) -> Self {
+        Dielectric { refractive_index }
+    }
+
+    /// reflectance approximates the Fresnel reflectance using Schlick's
+    /// approximation: `r0 + (1 - r0)(1 - cos_theta)^5`.
+    fn reflectance(cos_theta: f32, eta_ratio: f32) -> f32 {
+        let r0 = ((1.0 - eta_ratio) / (1.0 + eta_ratio)).powi(2);
+        r0 + (1.0 - r0) * (1.0 - cos_theta).powi(5)
+    }
+}
+
+impl Material for Dielectric {
+    fn scatter(&self, ray_in: &Ray, hit: &Hit, rng: &mut ThreadRng) -> Option<(Ray, Colour)> {
+        // A dielectric doesn't absorb anything.
+        let attenuation = Colour::new(1.0, 1.0, 1.0);
+
+        // eta_ratio is the ratio of the incident over the transmitted refractive index.
+        let eta_ratio = if hit.front_face {
+            1.0 / self.refractive_index
+        } else {
+            self.refractive_index
+        };
+
+        let cos_theta = (-ray_in.dir).dot(&hit.normal).min(1.0);
+        let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
+
+        // Total internal reflection kicks in when Snell's law has no solution,
+        // in which case the ray must reflect instead of refracting.
+        let cannot_refract = eta_ratio * sin_theta > 1.0;
+
+        let direction = if cannot_refract || Self::reflectance(cos_theta, eta_ratio) > rng.gen::<f32>() {
+            ray_in.dir.reflect(&hit.normal)
+        } else {
+            ray_in.dir.refract(&hit.normal, eta_ratio)
+        };
+
+        Some((Ray::new(hit.point, direction), attenuation))
+    }
+}
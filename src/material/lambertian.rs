@@ -0,0 +1,60 @@
+use std::f32::consts::PI;
+
+use rand::rngs::ThreadRng;
+
+use crate::{colour::Colour, hittable::Hit, material::Material, random, ray::Ray};
+
+/// PDF_EPSILON is the smallest pdf value scatter_importance will divide by. It guards
+/// against the near-tangent degeneracy where cos(theta) -> 0 and 1/pdf would
+/// otherwise explode into infinity/NaN.
+const PDF_EPSILON: f32 = 1e-4;
+
+/// Lambertian is a diffuse material that scatters rays in a cosine-weighted
+/// distribution about the surface normal.
+pub struct Lambertian {
+    pub albedo: Colour,
+}
+
+impl Lambertian {
+    /// Constructor.
+    pub fn new(albedo: Colour) -> Self {
+        Lambertian { albedo }
+    }
+}
+
+impl Material for Lambertian {
+    fn scatter(&self, _ray_in: &Ray, hit: &Hit, rng: &mut ThreadRng) -> Option<(Ray, Colour)> {
+        let mut scatter_dir = hit.normal + random::random_unit_vector(rng);
+
+        // Catch the degenerate case where the random unit vector cancels the
+        // normal out, which would otherwise produce a zero scatter direction.
+        if scatter_dir.near_zero() {
+            scatter_dir = hit.normal;
+        }
+
+        let scattered = Ray::new(hit.point, scatter_dir);
+        Some((scattered, self.albedo))
+    }
+
+    fn scatter_importance(
+        &self,
+        _ray_in: &Ray,
+        hit: &Hit,
+        rng: &mut ThreadRng,
+    ) -> Option<(Ray, Colour)> {
+        // Sample the outgoing direction on the cosine-weighted hemisphere about the normal.
+        let mut scatter_dir = hit.normal + random::random_unit_vector(rng);
+        if scatter_dir.near_zero() {
+            scatter_dir = hit.normal;
+        }
+        let scattered = Ray::new(hit.point, scatter_dir);
+
+        let cos_theta = scattered.dir.dot(&hit.normal).max(0.0);
+        // Clamp the pdf away from zero so a near-tangent direction can't blow
+        // the `cos(theta) / pdf` term up into infinity or NaN.
+        let pdf = (cos_theta / PI).max(PDF_EPSILON);
+
+        let brdf = self.albedo / PI;
+        Some((scattered, brdf * (cos_theta / pdf)))
+    }
+}
@@ -0,0 +1,38 @@
+use rand::rngs::ThreadRng;
+
+use crate::{colour::Colour, hittable::Hit, material::Material, random, ray::Ray};
+
+/// Metal is a reflective material, optionally fuzzed to blur the reflection.
+pub struct Metal {
+    pub albedo: Colour,
+    /// fuzz is the radius of the sphere used to perturb the reflected ray.
+    /// Values should stay in `[0, 1]`; anything above 1 just saturates.
+    pub fuzz: f32,
+}
+
+impl Metal {
+    /// Constructor.
+    pub fn new(albedo: Colour, fuzz: f32) -> Self {
+        Metal {
+            albedo,
+            fuzz: fuzz.min(1.0),
+        }
+    }
+}
+
+impl Material for Metal {
+    fn scatter(&self, ray_in: &Ray, hit: &Hit, rng: &mut ThreadRng) -> Option<(Ray, Colour)> {
+        let reflected = ray_in.dir.reflect(&hit.normal);
+        let scattered = Ray::new(
+            hit.point,
+            reflected + random::random_in_unit_sphere(rng) * self.fuzz,
+        );
+
+        // A scattered ray that ends up facing into the surface is absorbed.
+        if scattered.dir.dot(&hit.normal) > 0.0 {
+            Some((scattered, self.albedo))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,36 @@
+mod dielectric;
+mod lambertian;
+mod metal;
+
+use rand::rngs::ThreadRng;
+
+use crate::{colour::Colour, hittable::Hit, ray::Ray};
+
+pub use dielectric::Dielectric;
+pub use lambertian::Lambertian;
+pub use metal::Metal;
+
+/// Material is implemented by anything that can scatter an incident ray.
+/// `Send + Sync` is required so a material can be shared across render threads.
+pub trait Material: Send + Sync {
+    /// scatter returns the scattered ray and its attenuation, or `None` if
+    /// the incident ray is absorbed.
+    fn scatter(&self, ray_in: &Ray, hit: &Hit, rng: &mut ThreadRng) -> Option<(Ray, Colour)>;
+
+    /// scatter_importance importance-samples a scattered ray for Monte Carlo
+    /// path tracing, returning the ray and its fully weighted contribution
+    /// (`brdf * cos(theta) / pdf`), or `None` if the incident ray is absorbed.
+    ///
+    /// The default forwards to `scatter`, which is correct for materials
+    /// like Metal and Dielectric whose scattering is a specular
+    /// (optionally fuzzed, or randomly reflected-or-refracted) sample rather
+    /// than one drawn from a pdf over the hemisphere.
+    fn scatter_importance(
+        &self,
+        ray_in: &Ray,
+        hit: &Hit,
+        rng: &mut ThreadRng,
+    ) -> Option<(Ray, Colour)> {
+        self.scatter(ray_in, hit, rng)
+    }
+}
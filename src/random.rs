@@ -11,4 +11,26 @@ pub fn vec3_in_unit_disk(rng: &mut ThreadRng) -> Vec3 {
             return vec;
         }
     }
+}
+
+/// random_in_unit_sphere returns a random Vec3 inside a unit sphere.
+pub fn random_in_unit_sphere(rng: &mut ThreadRng) -> Vec3 {
+    // TODO: Is there a better way than this semi-brute-force?
+    loop {
+        let vec = Vec3::new(
+            rng.gen_range(-1.0..1.0),
+            rng.gen_range(-1.0..1.0),
+            rng.gen_range(-1.0..1.0),
+        );
+
+        if vec.dot_self() < 1.0 {
+            return vec;
+        }
+    }
+}
+
+/// random_unit_vector returns a random unit vector, uniformly distributed
+/// over the surface of the unit sphere.
+pub fn random_unit_vector(rng: &mut ThreadRng) -> Vec3 {
+    random_in_unit_sphere(rng).dir()
 }
\ No newline at end of file
@@ -1,32 +1,101 @@
+use std::cell::UnsafeCell;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
 use crate::colour::Colour;
-use image::{ImageBuffer, ImageError, Rgb};
+use image::{ImageBuffer, ImageError, ImageFormat, Rgb};
+use serde::Deserialize;
+
+/// OutputFormat selects how pixels are written to the output file.
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputFormat {
+    Jpg,
+    Png,
+    /// PpmAscii is the human-readable "P3" PPM variant.
+    PpmAscii,
+    /// PpmBinary is the raw-bytes "P6" PPM variant.
+    PpmBinary,
+}
 
 /// Encoder handles image encoding.
+///
+/// `put_pixel` takes `&self` rather than `&mut self` so that a single
+/// Encoder can be shared (by reference) across the render worker threads
+/// without a lock. This is only sound because tiled rendering hands out
+/// disjoint, non-overlapping pixel coordinates to each thread; see the
+/// `unsafe impl Sync` below.
 pub struct Encoder<'a> {
     file_path: &'a str,
-    imag_buff: ImageBuffer<Rgb<u8>, Vec<u8>>,
+    format: OutputFormat,
+    imag_buff: UnsafeCell<ImageBuffer<Rgb<u8>, Vec<u8>>>,
 }
 
 impl<'a> Encoder<'a> {
     /// Constructor.
-    pub fn new(file_path: &str, width: u32, height: u32) -> Encoder {
+    pub fn new(file_path: &'a str, width: u32, height: u32, format: OutputFormat) -> Encoder<'a> {
         Encoder {
-            file_path: file_path,
-            imag_buff: ImageBuffer::new(width, height),
+            file_path,
+            format,
+            imag_buff: UnsafeCell::new(ImageBuffer::new(width, height)),
         }
     }
 
     /// put_pixel puts the provided pixel value into the image.
-    pub fn put_pixel(&mut self, x: u32, y: u32, col: Colour) {
+    ///
+    /// Safety: the caller must ensure that no two threads ever call this
+    /// with the same `(x, y)` concurrently. Tiled rendering upholds this by
+    /// construction, since tiles partition the image into disjoint regions.
+    pub fn put_pixel(&self, x: u32, y: u32, col: Colour) {
         // Data conversions.
         let (r, g, b) = col.to_255();
 
-        // Write data.
-        self.imag_buff.put_pixel(x, y, Rgb([r, g, b]))
+        // SAFETY: see the doc comment above; concurrent calls never alias.
+        unsafe { (*self.imag_buff.get()).put_pixel(x, y, Rgb([r, g, b])) }
     }
 
-    /// save the image into the file.
+    /// save the image into the file, in the configured format.
     pub fn save(&self) -> Result<(), ImageError> {
-        self.imag_buff.save(&self.file_path)
+        match self.format {
+            OutputFormat::Jpg => self.imag_buff().save_with_format(self.file_path, ImageFormat::Jpeg),
+            OutputFormat::Png => self.imag_buff().save_with_format(self.file_path, ImageFormat::Png),
+            OutputFormat::PpmAscii => self.save_ppm_ascii(),
+            OutputFormat::PpmBinary => self.save_ppm_binary(),
+        }
     }
-}
\ No newline at end of file
+
+    /// imag_buff gives read access to the backing buffer. Only safe to call
+    /// once rendering has finished, i.e. no worker thread is still writing.
+    fn imag_buff(&self) -> &ImageBuffer<Rgb<u8>, Vec<u8>> {
+        unsafe { &*self.imag_buff.get() }
+    }
+
+    /// save_ppm_ascii writes the image as an ASCII ("P3") PPM file.
+    fn save_ppm_ascii(&self) -> Result<(), ImageError> {
+        let mut writer = BufWriter::new(File::create(self.file_path)?);
+        let (width, height) = self.imag_buff().dimensions();
+
+        write!(writer, "P3\n{} {}\n255\n", width, height)?;
+        for pixel in self.imag_buff().pixels() {
+            writeln!(writer, "{} {} {}", pixel[0], pixel[1], pixel[2])?;
+        }
+
+        Ok(())
+    }
+
+    /// save_ppm_binary writes the image as a raw-bytes ("P6") PPM file.
+    fn save_ppm_binary(&self) -> Result<(), ImageError> {
+        let mut writer = BufWriter::new(File::create(self.file_path)?);
+        let (width, height) = self.imag_buff().dimensions();
+
+        write!(writer, "P6\n{} {}\n255\n", width, height)?;
+        writer.write_all(self.imag_buff().as_raw())?;
+
+        Ok(())
+    }
+}
+
+// SAFETY: `put_pixel` only ever writes disjoint `(x, y)` coordinates across
+// threads (guaranteed by tiled rendering), so sharing `&Encoder` between
+// threads never causes a data race.
+unsafe impl Sync for Encoder<'_> {}
@@ -1,10 +1,33 @@
 mod encode;
+mod tile;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 // TODO: What's the difference between "use crate" and "use"?
-use crate::{camera::Camera, colour::Colour, ray::Ray};
+use crate::{camera::Camera, colour::Colour, hittable::Hittable, ray::Ray};
 use encode::Encoder;
 use image::ImageError;
 use rand::{rngs::ThreadRng, thread_rng, Rng};
+use serde::Deserialize;
+use tile::Tile;
+
+pub use encode::OutputFormat;
+
+/// TILE_SIZE is the side length (in pixels) of each block handed to a worker thread.
+const TILE_SIZE: u32 = 16;
+
+/// RenderMode selects how `trace_ray` shades a hit.
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RenderMode {
+    /// Normals shades each hit directly by its surface normal, ignoring materials.
+    Normals,
+    /// DiffuseBounce scatters off each material once per bounce without pdf weighting.
+    DiffuseBounce,
+    /// PathTrace is a proper Monte Carlo path tracer, importance-sampling
+    /// each scatter and weighting it by `brdf * cos(theta) / pdf`.
+    PathTrace,
+}
 
 /// Renderer uses raytracing to render images.
 pub struct Renderer<'a> {
@@ -15,6 +38,8 @@ pub struct Renderer<'a> {
 pub struct Options<'a> {
     /// camera acts as the source of light rays.
     pub camera: Camera,
+    /// world is the collection of objects the rays can hit.
+    pub world: Box<dyn Hittable>,
 
     // Image dimensions.
     pub image_width: u32,
@@ -25,6 +50,15 @@ pub struct Options<'a> {
     // max_diff_depth for nested reflections, refractions and diffusion.
     pub max_diff_depth: u32,
 
+    /// threads is the number of worker threads to render with.
+    /// `None` auto-detects the number of available CPUs.
+    pub threads: Option<usize>,
+
+    /// mode selects how hits are shaded.
+    pub mode: RenderMode,
+
+    /// output_format selects how the image is encoded to `output_file`.
+    pub output_format: OutputFormat,
     // output_file is the path to the output file.
     pub output_file: &'a str,
 }
@@ -35,28 +69,63 @@ impl<'a> Renderer<'a> {
         Renderer { opts }
     }
 
-    /// render triggers the rendering process.
+    /// render triggers the rendering process, splitting the image into tiles
+    /// and rendering them in parallel across a pool of worker threads.
     pub fn render(&self) -> Result<(), ImageError> {
-        let mut encoder = Encoder::new(
+        let encoder = Encoder::new(
             self.opts.output_file,
             self.opts.image_width,
             self.opts.image_height,
+            self.opts.output_format,
         );
 
-        // Nested loop over image height and width to handle each pixel.
-        for j in 0..self.opts.image_height {
-            for i in 0..self.opts.image_width {
+        // Split the image into disjoint tiles and hand them out to workers
+        // via a shared counter, so faster threads simply pick up more tiles.
+        let tiles = tile::tiles(self.opts.image_width, self.opts.image_height, TILE_SIZE);
+        let next_tile = AtomicUsize::new(0);
+
+        let num_threads = self.opts.threads.unwrap_or_else(num_cpus::get);
+
+        crossbeam::scope(|scope| {
+            for _ in 0..num_threads {
+                let next_tile = &next_tile;
+                let tiles = &tiles;
+                let encoder = &encoder;
+
+                // Camera and world are read-only, so they're shared by reference.
+                // The encoder is also shared by reference: tiles are disjoint, so
+                // no two threads ever write the same pixel, and no locking is needed.
+                scope.spawn(move |_| {
+                    let mut rng = thread_rng();
+                    loop {
+                        let index = next_tile.fetch_add(1, Ordering::Relaxed);
+                        let Some(tile) = tiles.get(index) else {
+                            break;
+                        };
+                        self.render_tile(tile, &mut rng, encoder);
+                    }
+                });
+            }
+        })
+        .expect("a worker thread panicked while rendering");
+
+        // Save the image.
+        encoder.save()
+    }
+
+    /// render_tile renders every pixel of the given tile, writing each one
+    /// straight into the shared encoder as soon as it's ready.
+    fn render_tile(&self, tile: &Tile, rng: &mut ThreadRng, encoder: &Encoder) {
+        for j in tile.y..tile.y + tile.height {
+            for i in tile.x..tile.x + tile.width {
                 // Reversing "j" because the encoder starts drawing from top-left.
                 let j_rev = self.opts.image_height - 1 - j;
                 // The rendering part.
-                let colour = self.render_pixel_aa(i as f32, j as f32, &mut thread_rng());
+                let colour = self.render_pixel_aa(i as f32, j as f32, rng);
                 // Write the pixel to the image.
                 encoder.put_pixel(i, j_rev, colour);
             }
         }
-
-        // Save the image.
-        encoder.save()
     }
 
     /// render_pixel_aa renders the given pixel with anti-aliasing.
@@ -82,17 +151,38 @@ impl<'a> Renderer<'a> {
         self.trace_ray(
             self.opts.camera.cast_ray(x, y, rng),
             self.opts.max_diff_depth,
+            rng,
         )
     }
 
     // trace_ray traces the provided ray upto the given diffusion depth and returns its final colour.
-    fn trace_ray(&self, ray: Ray, diff_depth: u32) -> Colour {
+    fn trace_ray(&self, ray: Ray, diff_depth: u32, rng: &mut ThreadRng) -> Colour {
         // If diffusion depth is reached, the ray is considered dead.
         // So, the colour is black.
         if diff_depth < 1 {
             return Colour::new(0.0, 0.0, 0.0);
         }
 
+        // Query the world for the closest hit and shade it per the render mode.
+        // 0.001 avoids "shadow acne" caused by hits at (or just behind) t = 0.
+        if let Some(hit) = self.opts.world.hit(&ray, 0.001, f32::INFINITY) {
+            let scatter_result = match self.opts.mode {
+                RenderMode::Normals => {
+                    let n = hit.normal;
+                    return Colour::new(n.x() + 1.0, n.y() + 1.0, n.z() + 1.0) * 0.5;
+                }
+                RenderMode::DiffuseBounce => hit.material.scatter(&ray, &hit, rng),
+                RenderMode::PathTrace => hit.material.scatter_importance(&ray, &hit, rng),
+            };
+
+            return match scatter_result {
+                Some((scattered, weight)) => {
+                    weight * self.trace_ray(scattered, diff_depth - 1, rng)
+                }
+                None => Colour::new(0.0, 0.0, 0.0),
+            };
+        }
+
         // Background colour or sky colour.
         let t = 0.5 * (ray.dir.y() + 1.0);
         Colour::new(1.0, 1.0, 1.0) * (1.0 - t) + Colour::new(0.5, 0.75, 1.0) * t
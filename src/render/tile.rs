@@ -0,0 +1,31 @@
+/// Tile is a rectangular block of pixels, used to split an image into
+/// independent chunks of work for multithreaded rendering.
+pub struct Tile {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// tiles splits an `image_width` x `image_height` image into `size` x `size`
+/// tiles, clipping the rightmost/bottommost tiles to fit the image bounds.
+pub fn tiles(image_width: u32, image_height: u32, size: u32) -> Vec<Tile> {
+    let mut result = Vec::new();
+
+    let mut y = 0;
+    while y < image_height {
+        let mut x = 0;
+        while x < image_width {
+            result.push(Tile {
+                x,
+                y,
+                width: size.min(image_width - x),
+                height: size.min(image_height - y),
+            });
+            x += size;
+        }
+        y += size;
+    }
+
+    result
+}
@@ -0,0 +1,173 @@
+use std::sync::Arc;
+use std::{fmt, fs, io};
+
+use serde::Deserialize;
+
+use crate::{
+    camera::{self, Camera},
+    colour::Colour,
+    hittable::{HittableList, Sphere},
+    material::{Dielectric, Lambertian, Material, Metal},
+    render::{self, OutputFormat, RenderMode},
+    vec3::Vec3,
+};
+
+/// Scene is the declarative, on-disk description of everything needed to
+/// render an image: the camera, the image/render settings, and the world.
+#[derive(Deserialize)]
+pub struct Scene {
+    camera: CameraConfig,
+    image: ImageConfig,
+    spheres: Vec<SphereConfig>,
+}
+
+/// CameraConfig mirrors `camera::Options`, minus the aspect ratio (which is
+/// derived from `ImageConfig` instead, since both need to agree on it).
+#[derive(Deserialize)]
+struct CameraConfig {
+    look_from: Vec3Config,
+    look_at: Vec3Config,
+    up: Vec3Config,
+    fov_vertical: f32,
+    aperture: f32,
+    focus_distance: f32,
+}
+
+/// ImageConfig holds the image dimensions and render settings.
+#[derive(Deserialize)]
+struct ImageConfig {
+    aspect_ratio: f32,
+    height: u32,
+    samples_per_pixel: u32,
+    max_diff_depth: u32,
+    threads: Option<usize>,
+    mode: RenderMode,
+    format: OutputFormat,
+    output_file: String,
+}
+
+/// SphereConfig describes one sphere primitive and its material.
+#[derive(Deserialize)]
+struct SphereConfig {
+    center: Vec3Config,
+    radius: f32,
+    material: MaterialConfig,
+}
+
+/// MaterialConfig is the on-disk representation of a `Material`.
+#[derive(Clone, Copy, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum MaterialConfig {
+    Lambertian { albedo: Vec3Config },
+    Metal { albedo: Vec3Config, fuzz: f32 },
+    Dielectric { refractive_index: f32 },
+}
+
+/// Vec3Config is the on-disk representation of a Vec3/Colour.
+#[derive(Clone, Copy, Deserialize)]
+struct Vec3Config {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+impl From<Vec3Config> for Vec3 {
+    fn from(v: Vec3Config) -> Self {
+        Vec3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3Config> for Colour {
+    fn from(v: Vec3Config) -> Self {
+        Colour::new(v.x, v.y, v.z)
+    }
+}
+
+impl Scene {
+    /// load reads and parses the scene description at `path`.
+    pub fn load(path: &str) -> Result<Scene, SceneError> {
+        let contents = fs::read_to_string(path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+
+    /// camera builds the Camera described by this scene.
+    pub fn camera(&self) -> Camera {
+        Camera::new(&camera::Options {
+            look_from: self.camera.look_from.into(),
+            look_at: self.camera.look_at.into(),
+            up: self.camera.up.into(),
+            aspect_ratio: self.image.aspect_ratio,
+            fov_vertical: self.camera.fov_vertical,
+            aperture: self.camera.aperture,
+            focus_distance: self.camera.focus_distance,
+        })
+    }
+
+    /// world builds the HittableList described by this scene's spheres.
+    pub fn world(&self) -> HittableList {
+        let mut world = HittableList::new();
+
+        for sphere in &self.spheres {
+            let material: Arc<dyn Material> = match sphere.material {
+                MaterialConfig::Lambertian { albedo } => Arc::new(Lambertian::new(albedo.into())),
+                MaterialConfig::Metal { albedo, fuzz } => {
+                    Arc::new(Metal::new(albedo.into(), fuzz))
+                }
+                MaterialConfig::Dielectric { refractive_index } => {
+                    Arc::new(Dielectric::new(refractive_index))
+                }
+            };
+
+            world.push(Box::new(Sphere::new(sphere.center.into(), sphere.radius, material)));
+        }
+
+        world
+    }
+
+    /// render_options builds the render::Options described by this scene.
+    /// The returned value borrows `output_file` from `self`.
+    pub fn render_options(&self) -> render::Options<'_> {
+        render::Options {
+            camera: self.camera(),
+            world: Box::new(self.world().into_bvh()),
+            image_height: self.image.height,
+            image_width: (self.image.height as f32 * self.image.aspect_ratio) as u32,
+            samples_per_pixel: self.image.samples_per_pixel,
+            max_diff_depth: self.image.max_diff_depth,
+            threads: self.image.threads,
+            mode: self.image.mode,
+            output_format: self.image.format,
+            output_file: &self.image.output_file,
+        }
+    }
+}
+
+/// SceneError wraps the failures that can occur while loading a scene file.
+#[derive(Debug)]
+pub enum SceneError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+}
+
+impl fmt::Display for SceneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SceneError::Io(err) => write!(f, "failed to read scene file: {err}"),
+            SceneError::Parse(err) => write!(f, "failed to parse scene file: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SceneError {}
+
+impl From<io::Error> for SceneError {
+    fn from(err: io::Error) -> Self {
+        SceneError::Io(err)
+    }
+}
+
+impl From<toml::de::Error> for SceneError {
+    fn from(err: toml::de::Error) -> Self {
+        SceneError::Parse(err)
+    }
+}
@@ -39,6 +39,29 @@ impl Vec3 {
         )
     }
 
+    /// reflect returns this vector reflected about the given normal.
+    pub fn reflect(&self, normal: &Vec3) -> Vec3 {
+        *self - *normal * (2.0 * self.dot(normal))
+    }
+
+    /// refract returns this vector (assumed a unit vector) refracted through
+    /// a surface with the given unit normal, using Snell's law. `eta_ratio`
+    /// is the ratio of the incident over the transmitted refractive index.
+    pub fn refract(&self, normal: &Vec3, eta_ratio: f32) -> Vec3 {
+        let cos_theta = (-*self).dot(normal).min(1.0);
+
+        let out_perp = (*self + *normal * cos_theta) * eta_ratio;
+        let out_parallel = *normal * -(1.0 - out_perp.dot_self()).abs().sqrt();
+
+        out_perp + out_parallel
+    }
+
+    /// near_zero returns true if all components of the vector are close to zero.
+    pub fn near_zero(&self) -> bool {
+        const EPS: f32 = 1e-8;
+        self.x().abs() < EPS && self.y().abs() < EPS && self.z().abs() < EPS
+    }
+
     /// x component of the vector.
     #[inline]
     pub fn x(&self) -> f32 {
@@ -56,6 +79,30 @@ impl Vec3 {
     pub fn z(&self) -> f32 {
         self.comp[2]
     }
+
+    /// axis returns the component along the given axis (0 = x, 1 = y, 2 = z).
+    #[inline]
+    pub fn axis(&self, axis: usize) -> f32 {
+        self.comp[axis]
+    }
+
+    /// min returns a vector with the component-wise minimum of the two vectors.
+    pub fn min(&self, other: &Vec3) -> Vec3 {
+        Vec3::new(
+            self.x().min(other.x()),
+            self.y().min(other.y()),
+            self.z().min(other.z()),
+        )
+    }
+
+    /// max returns a vector with the component-wise maximum of the two vectors.
+    pub fn max(&self, other: &Vec3) -> Vec3 {
+        Vec3::new(
+            self.x().max(other.x()),
+            self.y().max(other.y()),
+            self.z().max(other.z()),
+        )
+    }
 }
 
 /// Operator overload for addition.